@@ -1,8 +1,8 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Local};
-use clap::Parser;
-use semver::Version;
-use serde::Deserialize;
+use clap::{Parser, ValueEnum};
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::{self, Write};
 use std::process::{Command, Stdio};
@@ -12,9 +12,12 @@ use tabwriter::TabWriter;
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// The Helm chart repository source URL (e.g. https://bitnami-labs.github.io/sealed-secrets)
-    #[arg(long)]
-    source: String,
+    /// The Helm chart repository source: an HTTP(S) URL (e.g.
+    /// https://bitnami-labs.github.io/sealed-secrets), or a local directory / `file://` URL
+    /// containing an `index.yaml`. May be repeated to aggregate charts from multiple
+    /// repositories in one run.
+    #[arg(long, required = true)]
+    source: Vec<String>,
 
     /// (Optional) Filter by a specific chart name (case insensitive)
     #[arg(long)]
@@ -24,9 +27,56 @@ struct Args {
     #[arg(long = "type")]
     chart_type: Option<String>,
 
+    /// (Optional) Only show versions satisfying this semver requirement (e.g. ">=1.2, <2.0", "^3")
+    #[arg(long)]
+    version_constraint: Option<String>,
+
     /// Disable the pager (enabled by default on outputs longer than 25 lines)
     #[arg(long)]
     no_pager: bool,
+
+    /// Output format for the chart listing
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
+    /// Compare currently installed Helm releases against the repository and flag outdated charts
+    #[arg(long)]
+    check: bool,
+
+    /// With --check, exit non-zero if any installed release is outdated (also settable via
+    /// HELM_LIST_CHARTS_OUTDATED_FAIL=1/true/yes; clap's own `env` bool parsing only accepts
+    /// the literal strings "true"/"false", so the env var is read manually instead)
+    #[arg(long)]
+    outdated_fail: bool,
+
+    /// Collapse each chart down to only its newest stable release
+    #[arg(long)]
+    latest: bool,
+
+    /// With --latest, also show the newest prerelease when it's ahead of the newest stable release
+    #[arg(long)]
+    include_prereleases: bool,
+
+    /// Bypass the on-disk index cache entirely (neither read from nor write to it)
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Ignore any cached index and force a full re-fetch, refreshing the cache afterward
+    #[arg(long)]
+    refresh: bool,
+}
+
+/// The supported rendering formats for the chart listing.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    /// Tab-delimited table, optionally paged (the default).
+    Table,
+    /// A JSON array of `{repo, chart, versions}` objects.
+    Json,
+    /// A YAML array of `{repo, chart, versions}` objects.
+    Yaml,
+    /// A standalone, styled HTML page.
+    Html,
 }
 
 /// Represents the structure of the index.yaml file.
@@ -37,7 +87,7 @@ struct IndexFile {
 }
 
 /// Represents each chart version entry.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 struct ChartVersion {
     version: String,
     description: Option<String>,
@@ -49,6 +99,20 @@ struct ChartVersion {
     kube_version: Option<String>,
     #[serde(rename = "type")]
     chart_type: Option<String>,
+    /// With `--latest --include-prereleases`, the newest prerelease when it's ahead of `version`.
+    /// Never present in the upstream `index.yaml`; populated by `latest_only` and kept separate
+    /// from `version` so JSON/YAML output stays a parseable semver string.
+    #[serde(skip_deserializing, default)]
+    update_available: Option<String>,
+}
+
+/// A source repository and chart name paired with its (already filtered) list of versions, used as the
+/// common shape handed to every renderer below.
+#[derive(Debug, Serialize)]
+struct ChartEntry {
+    repo: String,
+    chart: String,
+    versions: Vec<ChartVersion>,
 }
 
 /// Entry point.
@@ -59,39 +123,39 @@ fn main() -> Result<()> {
 
 /// Main application logic.
 fn run(args: Args) -> Result<()> {
-    let index = fetch_index(&args.source)?;
+    // Fetch and merge every `--source` repository, keyed by (repo, chart) so the same chart
+    // name published by two different repositories doesn't collide.
+    let combined = fetch_combined_entries(&args.source, args.no_cache, args.refresh)?;
+
+    if args.check {
+        return run_check(&args, &combined);
+    }
 
-    // Collect the entries (chart names and their versions)
-    let mut entries: Vec<(&String, &Vec<ChartVersion>)> = index.entries.iter().collect();
+    // Collect the entries (repo/chart names and their versions)
+    let mut entries: Vec<(&(String, String), &Vec<ChartVersion>)> = combined.iter().collect();
 
     // If the user specified a chart name, filter to that chart (case insensitive).
     if let Some(ref chart_name) = args.chart {
-        entries.retain(|(name, _)| name.eq_ignore_ascii_case(chart_name));
+        entries.retain(|((_, name), _)| name.eq_ignore_ascii_case(chart_name));
         if entries.is_empty() {
             println!("No charts found for chart name: {}", chart_name);
             return Ok(());
         }
     }
 
-    // Determine if the pager should be enabled.
-    // Pager is enabled by default unless --no-pager is passed or the env vars are set.
-    let disable_pager_env =
-        std::env::var("HELM_LIST_CHARTS_NO_PAGER").is_ok() || std::env::var("NO_PAGER").is_ok();
-    let pager_enabled = !args.no_pager && !disable_pager_env;
-
-    // Buffer the output to a vector.
-    let mut output_buf = Vec::new();
-    {
-        let mut tw = TabWriter::new(&mut output_buf);
-        // Print header with columns: CHART, TYPE, VERSION, DESCRIPTION, APP VERSION, CREATED, KUBE VERSION.
-        writeln!(
-            tw,
-            "CHART\tTYPE\tVERSION\tDESCRIPTION\tAPP VERSION\tCREATED\tKUBE VERSION"
-        )?;
+    // If a semver constraint was given, parse it once upfront (e.g. ">=1.2, <2.0", "^3").
+    // By default `VersionReq::matches` already mirrors Helm/Cargo behavior: a constraint with
+    // no prerelease tag of its own does not match prerelease versions such as "1.3.0-rc1".
+    let version_constraint = args
+        .version_constraint
+        .as_ref()
+        .map(|s| VersionReq::parse(s).with_context(|| format!("Invalid --version-constraint: {}", s)))
+        .transpose()?;
 
-        // Process each chart entry.
-        for (chart_name, versions) in entries {
-            // If a type filter is specified, filter chart versions by chart_type (case insensitive).
+    // Apply the optional type and version-constraint filters, then drop charts left with no versions.
+    let chart_entries: Vec<ChartEntry> = entries
+        .into_iter()
+        .filter_map(|((repo, chart_name), versions)| {
             let filtered_versions: Vec<ChartVersion> =
                 if let Some(ref filter_type) = args.chart_type {
                     versions
@@ -108,29 +172,82 @@ fn run(args: Args) -> Result<()> {
                     versions.clone()
                 };
 
+            let filtered_versions: Vec<ChartVersion> = if let Some(ref req) = version_constraint {
+                filtered_versions
+                    .into_iter()
+                    .filter(|v| version_satisfies_constraint(&v.version, req))
+                    .collect()
+            } else {
+                filtered_versions
+            };
+
+            let filtered_versions = if args.latest {
+                latest_only(&filtered_versions, args.include_prereleases)
+            } else {
+                filtered_versions
+            };
+
             if filtered_versions.is_empty() {
-                continue;
+                None
+            } else {
+                Some(ChartEntry {
+                    repo: repo.clone(),
+                    chart: chart_name.clone(),
+                    versions: filtered_versions,
+                })
             }
+        })
+        .collect();
 
-            let lines = format_chart_versions(chart_name, &filtered_versions);
-            for line in lines {
-                writeln!(tw, "{}", line)?;
-            }
+    // Only the table format uses the pager; machine-readable formats are meant
+    // to be piped straight into scripts, dashboards, or static-site tooling.
+    if args.output == OutputFormat::Table {
+        let output_str = render_table(&chart_entries)?;
+
+        // Check if there's just one line: the titles. If so,
+        // print a message and return early.
+        if output_str.lines().count() == 1 {
+            return Err(anyhow::anyhow!("No charts found."));
         }
-        tw.flush()
-            .with_context(|| "Failed to flush tabwriter output")?;
-    }
 
-    // Convert the output to a string.
-    let output_str = String::from_utf8(output_buf)
-        .with_context(|| "Failed to convert output buffer to UTF-8")?;
+        return write_paged(&output_str, args.no_pager);
+    }
 
-    // Check if there's just one line: the titles. If so,
-    // print a message and return early.
-    if output_str.lines().count() == 1 {
+    if chart_entries.is_empty() {
         return Err(anyhow::anyhow!("No charts found."));
     }
 
+    let output_str = match args.output {
+        OutputFormat::Json => render_json(&chart_entries)?,
+        OutputFormat::Yaml => render_yaml(&chart_entries)?,
+        OutputFormat::Html => render_html(&chart_entries),
+        OutputFormat::Table => unreachable!("table output is handled above"),
+    };
+
+    io::stdout()
+        .write_all(output_str.as_bytes())
+        .with_context(|| "Failed to write output to stdout")?;
+
+    Ok(())
+}
+
+/// Returns whether `version` satisfies `req`. Mirrors Cargo/Helm semver semantics: a constraint
+/// with no prerelease tag of its own (e.g. ">=1.2, <2.0") does not match prerelease versions such
+/// as "1.3.0-rc1", and a version that fails to parse never matches.
+fn version_satisfies_constraint(version: &str, req: &VersionReq) -> bool {
+    Version::parse(version)
+        .map(|parsed| req.matches(&parsed))
+        .unwrap_or(false)
+}
+
+/// Writes `output_str` to the pager (if enabled and the output is long enough to warrant it)
+/// or directly to stdout otherwise.
+fn write_paged(output_str: &str, no_pager: bool) -> Result<()> {
+    // Pager is enabled by default unless --no-pager is passed or the env vars are set.
+    let disable_pager_env =
+        std::env::var("HELM_LIST_CHARTS_NO_PAGER").is_ok() || std::env::var("NO_PAGER").is_ok();
+    let pager_enabled = !no_pager && !disable_pager_env;
+
     if pager_enabled && output_str.lines().count() >= 25 {
         // Determine pager program, defaulting to "less".
         let pager = std::env::var("PAGER").unwrap_or_else(|_| "less".to_string());
@@ -151,7 +268,6 @@ fn run(args: Args) -> Result<()> {
             .wait()
             .with_context(|| "Pager process encountered an error")?;
     } else {
-        // No pager: write directly to stdout.
         io::stdout()
             .write_all(output_str.as_bytes())
             .with_context(|| "Failed to write output to stdout")?;
@@ -160,15 +276,332 @@ fn run(args: Args) -> Result<()> {
     Ok(())
 }
 
-/// Fetches the index.yaml file from the given source URL and parses it.
-fn fetch_index(source: &str) -> Result<IndexFile> {
-    // Build the URL for the index.yaml (ensure there is no trailing slash)
+/// A currently-installed Helm release, as reported by `helm list --all-namespaces --output json`.
+#[derive(Debug, Deserialize)]
+struct HelmRelease {
+    name: String,
+    chart: String,
+}
+
+/// The drift status of an installed release relative to the repository's newest version.
+#[derive(Debug, PartialEq, Eq)]
+enum CheckStatus {
+    UpToDate,
+    Outdated,
+    NotFound,
+    /// The chart was found in the repository, but either the installed or the latest version
+    /// string didn't parse as semver, so drift can't actually be determined. Treated the same
+    /// as `Outdated` for `--outdated-fail` so it can't silently mask real drift in CI.
+    Unknown,
+}
+
+impl CheckStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::UpToDate => "UP-TO-DATE",
+            CheckStatus::Outdated => "OUTDATED",
+            CheckStatus::NotFound => "NOT-FOUND",
+            CheckStatus::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// Derives a release's `CheckStatus` from its installed version and the newest version found in
+/// the repository (if any chart with that name was found at all).
+fn compute_status(installed_version: &str, latest_version: Option<&str>) -> CheckStatus {
+    let Some(latest) = latest_version else {
+        return CheckStatus::NotFound;
+    };
+
+    match (Version::parse(installed_version), Version::parse(latest)) {
+        (Ok(installed), Ok(latest_parsed)) => {
+            if latest_parsed > installed {
+                CheckStatus::Outdated
+            } else {
+                CheckStatus::UpToDate
+            }
+        }
+        _ => CheckStatus::Unknown,
+    }
+}
+
+/// Runs `--check` mode: reads the currently installed Helm releases, cross-references each
+/// against the newest version of the matching chart across every fetched `--source`, and
+/// prints a drift report.
+fn run_check(args: &Args, combined: &HashMap<(String, String), Vec<ChartVersion>>) -> Result<()> {
+    let releases = fetch_helm_releases()?;
+
+    let mut output_buf = Vec::new();
+    let mut any_outdated = false;
+    {
+        let mut tw = TabWriter::new(&mut output_buf);
+        writeln!(tw, "RELEASE\tCHART\tINSTALLED\tLATEST\tSTATUS")?;
+
+        for release in &releases {
+            let (chart_name, installed_version) = split_chart_field(&release.chart);
+            let latest_version = latest_stable_version(combined, &chart_name);
+
+            let status = compute_status(&installed_version, latest_version.as_deref());
+
+            if status == CheckStatus::Outdated || status == CheckStatus::Unknown {
+                any_outdated = true;
+            }
+
+            writeln!(
+                tw,
+                "{}\t{}\t{}\t{}\t{}",
+                release.name,
+                chart_name,
+                installed_version,
+                latest_version.as_deref().unwrap_or("<unknown>"),
+                status.as_str(),
+            )?;
+        }
+        tw.flush()
+            .with_context(|| "Failed to flush tabwriter output")?;
+    }
+
+    let output_str =
+        String::from_utf8(output_buf).with_context(|| "Failed to convert output buffer to UTF-8")?;
+
+    if output_str.lines().count() == 1 {
+        return Err(anyhow::anyhow!("No installed releases found."));
+    }
+
+    write_paged(&output_str, args.no_pager)?;
+
+    if (args.outdated_fail || env_flag("HELM_LIST_CHARTS_OUTDATED_FAIL")) && any_outdated {
+        return Err(anyhow::anyhow!(
+            "One or more installed releases are outdated or of unknown drift status."
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reads a boolean CI-style environment variable, accepting the conventional `1`/`0`,
+/// `true`/`false`, and `yes`/`no` spellings (case insensitive) rather than clap's stricter
+/// `env`-attribute bool parsing, which only accepts the literal strings "true"/"false".
+fn env_flag(name: &str) -> bool {
+    std::env::var(name)
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false)
+}
+
+/// Shells out to `helm list --all-namespaces --output json` and parses the result.
+fn fetch_helm_releases() -> Result<Vec<HelmRelease>> {
+    let output = Command::new("helm")
+        .args(["list", "--all-namespaces", "--output", "json"])
+        .output()
+        .with_context(|| "Failed to run `helm list --all-namespaces --output json`")?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "`helm list` exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    serde_json::from_slice(&output.stdout).with_context(|| "Failed to parse `helm list` JSON output")
+}
+
+/// Splits a release's `chart` field (e.g. `"nginx-13.2.0"`) into its chart name and version by
+/// finding the rightmost `-` after which the remainder parses as a semantic version.
+fn split_chart_field(chart: &str) -> (String, String) {
+    for (idx, _) in chart.match_indices('-').rev() {
+        let candidate_version = &chart[idx + 1..];
+        if Version::parse(candidate_version).is_ok() {
+            return (chart[..idx].to_string(), candidate_version.to_string());
+        }
+    }
+    (chart.to_string(), "<unspecified>".to_string())
+}
+
+/// Finds the newest stable (non-prerelease) version of `chart_name` across every repository in
+/// `combined`, if any.
+fn latest_stable_version(
+    combined: &HashMap<(String, String), Vec<ChartVersion>>,
+    chart_name: &str,
+) -> Option<String> {
+    let matching_versions = combined
+        .iter()
+        .filter(|((_, name), _)| name.eq_ignore_ascii_case(chart_name))
+        .flat_map(|(_, versions)| versions.iter().cloned())
+        .collect::<Vec<_>>();
+
+    if matching_versions.is_empty() {
+        return None;
+    }
+
+    sorted_versions_desc(&matching_versions)
+        .into_iter()
+        .find(|v| {
+            Version::parse(&v.version)
+                .map(|parsed| parsed.pre.is_empty())
+                .unwrap_or(false)
+        })
+        .map(|v| v.version)
+}
+
+/// Renders the chart entries as a tab-delimited table (the default, pager-friendly format).
+fn render_table(chart_entries: &[ChartEntry]) -> Result<String> {
+    let mut output_buf = Vec::new();
+    {
+        let mut tw = TabWriter::new(&mut output_buf);
+        // Print header with columns: REPO, CHART, TYPE, VERSION, DESCRIPTION, APP VERSION, CREATED, KUBE VERSION.
+        writeln!(
+            tw,
+            "REPO\tCHART\tTYPE\tVERSION\tDESCRIPTION\tAPP VERSION\tCREATED\tKUBE VERSION"
+        )?;
+
+        for entry in chart_entries {
+            let lines = format_chart_versions(&entry.repo, &entry.chart, &entry.versions);
+            for line in lines {
+                writeln!(tw, "{}", line)?;
+            }
+        }
+        tw.flush()
+            .with_context(|| "Failed to flush tabwriter output")?;
+    }
+
+    String::from_utf8(output_buf).with_context(|| "Failed to convert output buffer to UTF-8")
+}
+
+/// Renders the chart entries as a pretty-printed JSON array.
+fn render_json(chart_entries: &[ChartEntry]) -> Result<String> {
+    serde_json::to_string_pretty(chart_entries).with_context(|| "Failed to serialize output as JSON")
+}
+
+/// Renders the chart entries as a YAML array.
+fn render_yaml(chart_entries: &[ChartEntry]) -> Result<String> {
+    serde_yaml::to_string(chart_entries).with_context(|| "Failed to serialize output as YAML")
+}
+
+/// Renders the chart entries as a standalone HTML page with a single styled `<table>`,
+/// using the same eight columns as the table format.
+fn render_html(chart_entries: &[ChartEntry]) -> String {
+    let mut rows = String::new();
+    for entry in chart_entries {
+        for v in sorted_versions_desc(&entry.versions) {
+            rows.push_str(&format!(
+                "      <tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                html_escape(&entry.repo),
+                html_escape(&entry.chart),
+                html_escape(v.chart_type.as_deref().unwrap_or("<unspecified>")),
+                html_escape(&display_version(&v)),
+                html_escape(v.description.as_deref().unwrap_or("")),
+                html_escape(v.app_version.as_deref().unwrap_or("<unspecified>")),
+                html_escape(&format_created(&v.created)),
+                html_escape(v.kube_version.as_deref().unwrap_or("<unspecified>")),
+            ));
+        }
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>Helm Chart Listing</title>
+  <style>
+    body {{ font-family: sans-serif; margin: 2rem; }}
+    table {{ border-collapse: collapse; width: 100%; }}
+    th, td {{ border: 1px solid #ccc; padding: 0.4rem 0.6rem; text-align: left; }}
+    th {{ background: #f5f5f5; }}
+    tr:nth-child(even) {{ background: #fafafa; }}
+  </style>
+</head>
+<body>
+  <table>
+    <thead>
+      <tr><th>REPO</th><th>CHART</th><th>TYPE</th><th>VERSION</th><th>DESCRIPTION</th><th>APP VERSION</th><th>CREATED</th><th>KUBE VERSION</th></tr>
+    </thead>
+    <tbody>
+{rows}    </tbody>
+  </table>
+</body>
+</html>
+"#,
+        rows = rows
+    )
+}
+
+/// Escapes the minimal set of characters needed for safe inclusion in HTML text nodes.
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Fetches and merges the `index.yaml` of every `source` repository into a single map keyed by
+/// `(repo, chart)`, so the same chart name published by two different repositories doesn't collide.
+fn fetch_combined_entries(
+    sources: &[String],
+    no_cache: bool,
+    refresh: bool,
+) -> Result<HashMap<(String, String), Vec<ChartVersion>>> {
+    let mut combined = HashMap::new();
+    for source in sources {
+        let index = fetch_index(source, no_cache, refresh)?;
+        for (chart_name, versions) in index.entries {
+            combined.insert((source.clone(), chart_name), versions);
+        }
+    }
+    Ok(combined)
+}
+
+/// Fetches the index.yaml for `source` and parses it.
+///
+/// `source` may be an HTTP(S) URL, a local directory, or a `file://` URL; in the latter two
+/// cases the index is read straight off disk and the cache below does not apply. For HTTP(S)
+/// sources, the fetched body plus its `ETag`/`Last-Modified` are cached on disk under a key
+/// derived from `source`, and revalidated with `If-None-Match`/`If-Modified-Since` on the next
+/// call, reusing the cached body on a `304 Not Modified`. `no_cache` bypasses the cache
+/// entirely; `refresh` ignores any cached copy and forces a full re-fetch.
+fn fetch_index(source: &str, no_cache: bool, refresh: bool) -> Result<IndexFile> {
+    if let Some(path) = local_index_path(source) {
+        let body = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read local index file: {}", path))?;
+        return parse_index(&body);
+    }
+
     let url = format!("{}/index.yaml", source.trim_end_matches('/'));
-    let response =
-        reqwest::blocking::get(&url).with_context(|| format!("Failed to GET from URL: {}", url))?;
-    let body = response
-        .text()
-        .with_context(|| "Failed to read response body as text")?;
+
+    if no_cache {
+        let response = http_get(&url, None)?;
+        return parse_index(&response.body);
+    }
+
+    let cached = if refresh { None } else { read_cache(source) };
+    let response = http_get(&url, cached.as_ref().map(|c| &c.meta))?;
+
+    let body = match (response.not_modified, cached) {
+        // The server only sends 304 in response to the conditional headers we attach when we
+        // already have a cached copy, so this case always has one; but if a non-compliant
+        // server sends 304 for an unconditional request anyway, don't trust its empty body.
+        (true, Some(cached)) => cached.body,
+        (true, None) => {
+            return Err(anyhow::anyhow!(
+                "Received an unexpected 304 Not Modified with no cached copy to fall back to for {}",
+                url
+            ));
+        }
+        (false, _) => {
+            let meta = CacheMeta {
+                etag: response.etag,
+                last_modified: response.last_modified,
+            };
+            // A cache write failure shouldn't fail a request that otherwise succeeded; the
+            // fetched body is still used below, just without being persisted for next time.
+            if let Err(err) = write_cache(source, &response.body, &meta) {
+                eprintln!("warning: failed to write index cache for {}: {:#}", source, err);
+            }
+            response.body
+        }
+    };
+
     parse_index(&body)
 }
 
@@ -177,6 +610,133 @@ fn parse_index(yaml: &str) -> Result<IndexFile> {
     serde_yaml::from_str(yaml).with_context(|| "Failed to parse YAML index file")
 }
 
+/// If `source` refers to a local directory or `file://` URL, returns the path to the
+/// `index.yaml` expected inside it. Returns `None` for HTTP(S) sources.
+fn local_index_path(source: &str) -> Option<String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        return None;
+    }
+    let base = source.strip_prefix("file://").unwrap_or(source);
+    Some(format!("{}/index.yaml", base.trim_end_matches('/')))
+}
+
+/// The subset of an HTTP response the on-disk index cache cares about.
+struct HttpResponse {
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    not_modified: bool,
+}
+
+/// Performs a GET against `url`, optionally sending `If-None-Match`/`If-Modified-Since` headers
+/// built from a previously cached `CacheMeta`.
+fn http_get(url: &str, conditional: Option<&CacheMeta>) -> Result<HttpResponse> {
+    let client = reqwest::blocking::Client::new();
+    let mut request = client.get(url);
+    if let Some(meta) = conditional {
+        if let Some(etag) = &meta.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &meta.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let response = request
+        .send()
+        .with_context(|| format!("Failed to GET from URL: {}", url))?;
+
+    let not_modified = response.status() == reqwest::StatusCode::NOT_MODIFIED;
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string());
+    let last_modified = response
+        .headers()
+        .get(reqwest::header::LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string());
+
+    let body = if not_modified {
+        String::new()
+    } else {
+        response
+            .text()
+            .with_context(|| "Failed to read response body as text")?
+    };
+
+    Ok(HttpResponse {
+        body,
+        etag,
+        last_modified,
+        not_modified,
+    })
+}
+
+/// The cached `ETag`/`Last-Modified` pair stored alongside a cached `index.yaml` body.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheMeta {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// A previously cached index body plus the revalidation metadata it was stored with.
+struct CachedIndex {
+    body: String,
+    meta: CacheMeta,
+}
+
+/// Returns (creating if necessary) the directory used to cache fetched `index.yaml` files.
+fn cache_dir() -> Result<std::path::PathBuf> {
+    let dir = dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("helm-list-charts");
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create cache directory: {}", dir.display()))?;
+    Ok(dir)
+}
+
+/// Derives a filesystem-safe cache key from a source URL.
+fn cache_key(source: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Loads the cached body and metadata for `source`, if any has been stored.
+fn read_cache(source: &str) -> Option<CachedIndex> {
+    let dir = cache_dir().ok()?;
+    let key = cache_key(source);
+
+    let body = std::fs::read_to_string(dir.join(format!("{key}.yaml"))).ok()?;
+    let meta = std::fs::read_to_string(dir.join(format!("{key}.meta.json")))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+
+    Some(CachedIndex { body, meta })
+}
+
+/// Stores the fetched body and its revalidation metadata for `source`.
+fn write_cache(source: &str, body: &str, meta: &CacheMeta) -> Result<()> {
+    let dir = cache_dir()?;
+    let key = cache_key(source);
+
+    std::fs::write(dir.join(format!("{key}.yaml")), body)
+        .with_context(|| "Failed to write cached index.yaml")?;
+    std::fs::write(
+        dir.join(format!("{key}.meta.json")),
+        serde_json::to_string(meta).with_context(|| "Failed to serialize cache metadata")?,
+    )
+    .with_context(|| "Failed to write cache metadata")?;
+
+    Ok(())
+}
+
 /// Formats the given `created` field value into a human-readable form.
 /// If parsing fails or the field is not available, returns "<unspecified>".
 fn format_created(created: &Option<String>) -> String {
@@ -214,14 +774,11 @@ fn ellipsize(text: &str, max_chars: usize) -> String {
     format!("{}...", trimmed)
 }
 
-/// Formats the list of chart versions into tab-delimited lines.
-/// The versions are sorted in descending order (newest version at the top).
-/// Each row includes the columns: CHART, TYPE, VERSION, DESCRIPTION, APP VERSION, CREATED, KUBE VERSION.
-fn format_chart_versions(chart_name: &str, versions: &[ChartVersion]) -> Vec<String> {
-    // Clone and sort versions in descending order.
+/// Sorts a copy of `versions` in descending order (newest version first), falling back to a
+/// plain string comparison for entries that don't parse as semantic versions.
+fn sorted_versions_desc(versions: &[ChartVersion]) -> Vec<ChartVersion> {
     let mut sorted_versions = versions.to_vec();
     sorted_versions.sort_by(|a, b| {
-        // Attempt to parse the version strings as semantic versions.
         let ver_a = Version::parse(&a.version);
         let ver_b = Version::parse(&b.version);
         match (ver_a, ver_b) {
@@ -229,15 +786,79 @@ fn format_chart_versions(chart_name: &str, versions: &[ChartVersion]) -> Vec<Str
             _ => b.version.cmp(&a.version),
         }
     });
+    sorted_versions
+}
+
+/// Collapses `versions` down to a single newest-stable-release entry (for `--latest`).
+///
+/// Partitions the already-sorted versions into stable vs prerelease buckets using
+/// `semver::Version::pre.is_empty()` and takes the max of each. When `include_prereleases` is
+/// set and the newest prerelease is ahead of the newest stable release, it's recorded in the
+/// returned entry's `update_available` field rather than folded into `version`, so `version`
+/// stays a parseable semver string in the JSON/YAML output too.
+fn latest_only(versions: &[ChartVersion], include_prereleases: bool) -> Vec<ChartVersion> {
+    let sorted = sorted_versions_desc(versions);
+
+    let stable_max = sorted.iter().find(|v| {
+        Version::parse(&v.version)
+            .map(|parsed| parsed.pre.is_empty())
+            .unwrap_or(false)
+    });
+
+    let Some(stable_max) = stable_max else {
+        // No version parses as a stable semver; fall back to the newest entry as-is.
+        return sorted.into_iter().take(1).collect();
+    };
+
+    if include_prereleases {
+        let prerelease_max = sorted.iter().find(|v| {
+            Version::parse(&v.version)
+                .map(|parsed| !parsed.pre.is_empty())
+                .unwrap_or(false)
+        });
+
+        if let Some(prerelease_max) = prerelease_max {
+            let stable_version = Version::parse(&stable_max.version).ok();
+            let prerelease_version = Version::parse(&prerelease_max.version).ok();
+            if let (Some(stable_version), Some(prerelease_version)) =
+                (stable_version, prerelease_version)
+            {
+                if prerelease_version > stable_version {
+                    let mut hinted = stable_max.clone();
+                    hinted.update_available = Some(prerelease_max.version.clone());
+                    return vec![hinted];
+                }
+            }
+        }
+    }
+
+    vec![stable_max.clone()]
+}
+
+/// The text shown in the VERSION column (table/HTML) for a chart version, annotated with its
+/// `update_available` hint when present, e.g. `"1.4.0 (1.5.0-rc2 available)"`.
+fn display_version(v: &ChartVersion) -> String {
+    match &v.update_available {
+        Some(newer) => format!("{} ({} available)", v.version, newer),
+        None => v.version.clone(),
+    }
+}
+
+/// Formats the list of chart versions into tab-delimited lines.
+/// The versions are sorted in descending order (newest version at the top).
+/// Each row includes the columns: REPO, CHART, TYPE, VERSION, DESCRIPTION, APP VERSION, CREATED, KUBE VERSION.
+fn format_chart_versions(repo: &str, chart_name: &str, versions: &[ChartVersion]) -> Vec<String> {
+    let sorted_versions = sorted_versions_desc(versions);
 
     let mut lines = Vec::new();
     for v in sorted_versions.iter() {
         let desc_excerpt = ellipsize(v.description.as_deref().unwrap_or(""), 50);
         lines.push(format!(
-            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            repo,
             chart_name,
             v.chart_type.as_deref().unwrap_or("<unspecified>"),
-            v.version,
+            display_version(v),
             desc_excerpt,
             v.app_version.as_deref().unwrap_or("<unspecified>"),
             format_created(&v.created),
@@ -263,6 +884,7 @@ mod tests {
                 created: Some("2025-02-13T12:42:23.967760696Z".to_string()),
                 kube_version: Some(">= 1.19.0-0".to_string()),
                 chart_type: Some("application".to_string()),
+                update_available: None,
             },
             ChartVersion {
                 version: "2.0.0".to_string(),
@@ -271,10 +893,11 @@ mod tests {
                 created: None,
                 kube_version: None,
                 chart_type: None,
+                update_available: None,
             },
         ];
 
-        let lines = format_chart_versions(chart_name, &versions);
+        let lines = format_chart_versions("my-repo", chart_name, &versions);
         assert_eq!(lines.len(), 2);
         // Ensure that the newest version is first.
         assert!(lines[0].contains("2.0.0"));
@@ -348,4 +971,267 @@ entries:
         let result = parse_index(invalid_yaml);
         assert!(result.is_err(), "Expected an error for invalid YAML");
     }
+
+    /// Builds a minimal `ChartVersion` for tests that only care about `version`.
+    fn version_only(version: &str) -> ChartVersion {
+        ChartVersion {
+            version: version.to_string(),
+            description: None,
+            app_version: None,
+            created: None,
+            kube_version: None,
+            chart_type: None,
+            update_available: None,
+        }
+    }
+
+    #[test]
+    fn test_html_escape_escapes_reserved_characters() {
+        assert_eq!(
+            html_escape(r#"<script>"Tom & Jerry"</script>"#),
+            "&lt;script&gt;&quot;Tom &amp; Jerry&quot;&lt;/script&gt;"
+        );
+    }
+
+    #[test]
+    fn test_html_escape_leaves_plain_text_untouched() {
+        assert_eq!(html_escape("plain text"), "plain text");
+    }
+
+    #[test]
+    fn test_render_html_uses_display_version_and_escapes_fields() {
+        let mut v = version_only("1.0.0");
+        v.description = Some("<b>bold</b>".to_string());
+        v.update_available = Some("1.1.0".to_string());
+        let entries = vec![ChartEntry {
+            repo: "my-repo".to_string(),
+            chart: "my-chart".to_string(),
+            versions: vec![v],
+        }];
+
+        let html = render_html(&entries);
+        assert!(html.contains("1.0.0 (1.1.0 available)"));
+        assert!(html.contains("&lt;b&gt;bold&lt;/b&gt;"));
+        assert!(!html.contains("<b>bold</b>"));
+    }
+
+    #[test]
+    fn test_render_json_keeps_version_machine_readable() {
+        let mut v = version_only("1.4.0");
+        v.update_available = Some("1.5.0-rc2".to_string());
+        let entries = vec![ChartEntry {
+            repo: "my-repo".to_string(),
+            chart: "my-chart".to_string(),
+            versions: vec![v],
+        }];
+
+        let json = render_json(&entries).expect("render_json failed");
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("invalid JSON");
+        let version = parsed[0]["versions"][0]["version"].as_str().unwrap();
+        assert_eq!(version, "1.4.0");
+        assert_eq!(
+            parsed[0]["versions"][0]["update_available"].as_str(),
+            Some("1.5.0-rc2")
+        );
+        // The raw string never appears un-parsed inside the JSON version field.
+        assert!(Version::parse(version).is_ok());
+    }
+
+    #[test]
+    fn test_render_yaml_keeps_version_machine_readable() {
+        let entries = vec![ChartEntry {
+            repo: "my-repo".to_string(),
+            chart: "my-chart".to_string(),
+            versions: vec![version_only("1.4.0")],
+        }];
+
+        let yaml = render_yaml(&entries).expect("render_yaml failed");
+        assert!(yaml.contains("version: 1.4.0"));
+        // No human-readable hint gets folded into the machine-readable version field.
+        assert!(!yaml.contains("1.4.0 ("));
+    }
+
+    #[test]
+    fn test_version_satisfies_constraint_excludes_prerelease_by_default() {
+        let req = VersionReq::parse(">=1.0.0, <2.0.0").unwrap();
+        assert!(version_satisfies_constraint("1.5.0", &req));
+        assert!(!version_satisfies_constraint("2.0.0", &req));
+        // A constraint without its own prerelease tag doesn't match prerelease versions.
+        assert!(!version_satisfies_constraint("1.5.0-rc1", &req));
+    }
+
+    #[test]
+    fn test_version_satisfies_constraint_unparseable_version_does_not_match() {
+        let req = VersionReq::parse("*").unwrap();
+        assert!(!version_satisfies_constraint("not-a-version", &req));
+    }
+
+    #[test]
+    fn test_split_chart_field_with_version_suffix() {
+        let (name, version) = split_chart_field("nginx-13.2.0");
+        assert_eq!(name, "nginx");
+        assert_eq!(version, "13.2.0");
+    }
+
+    #[test]
+    fn test_split_chart_field_with_hyphenated_name_and_prerelease() {
+        let (name, version) = split_chart_field("cert-manager-1.14.0-rc1");
+        assert_eq!(name, "cert-manager");
+        assert_eq!(version, "1.14.0-rc1");
+    }
+
+    #[test]
+    fn test_split_chart_field_without_parseable_version() {
+        let (name, version) = split_chart_field("just-a-name");
+        assert_eq!(name, "just-a-name");
+        assert_eq!(version, "<unspecified>");
+    }
+
+    #[test]
+    fn test_compute_status_up_to_date() {
+        assert_eq!(
+            compute_status("1.0.0", Some("1.0.0")),
+            CheckStatus::UpToDate
+        );
+    }
+
+    #[test]
+    fn test_compute_status_outdated() {
+        assert_eq!(
+            compute_status("1.0.0", Some("1.1.0")),
+            CheckStatus::Outdated
+        );
+    }
+
+    #[test]
+    fn test_compute_status_not_found() {
+        assert_eq!(compute_status("1.0.0", None), CheckStatus::NotFound);
+    }
+
+    #[test]
+    fn test_compute_status_unknown_on_unparseable_version() {
+        assert_eq!(
+            compute_status("<unspecified>", Some("1.1.0")),
+            CheckStatus::Unknown
+        );
+        assert_eq!(
+            compute_status("1.0.0", Some("<unspecified>")),
+            CheckStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_fetch_combined_entries_merges_same_chart_name_across_sources() {
+        let dir = std::env::temp_dir().join(format!(
+            "helm-list-charts-test-merge-{:?}",
+            std::thread::current().id()
+        ));
+        let repo_a = dir.join("repo-a");
+        let repo_b = dir.join("repo-b");
+        std::fs::create_dir_all(&repo_a).unwrap();
+        std::fs::create_dir_all(&repo_b).unwrap();
+        std::fs::write(
+            repo_a.join("index.yaml"),
+            "entries:\n  shared-chart:\n    - version: \"1.0.0\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            repo_b.join("index.yaml"),
+            "entries:\n  shared-chart:\n    - version: \"2.0.0\"\n",
+        )
+        .unwrap();
+
+        let sources = vec![
+            repo_a.to_string_lossy().to_string(),
+            repo_b.to_string_lossy().to_string(),
+        ];
+        let combined = fetch_combined_entries(&sources, true, false).expect("fetch failed");
+
+        // Same chart name from two sources lands under two distinct (repo, chart) keys rather
+        // than one clobbering the other.
+        assert_eq!(combined.len(), 2);
+        assert_eq!(
+            combined[&(sources[0].clone(), "shared-chart".to_string())][0].version,
+            "1.0.0"
+        );
+        assert_eq!(
+            combined[&(sources[1].clone(), "shared-chart".to_string())][0].version,
+            "2.0.0"
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_latest_only_without_prereleases_keeps_newest_stable() {
+        let versions = vec![
+            version_only("1.0.0"),
+            version_only("1.5.0"),
+            version_only("1.6.0-rc1"),
+        ];
+        let result = latest_only(&versions, false);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].version, "1.5.0");
+        assert!(result[0].update_available.is_none());
+    }
+
+    #[test]
+    fn test_latest_only_with_prereleases_sets_update_available_hint() {
+        let versions = vec![
+            version_only("1.0.0"),
+            version_only("1.5.0"),
+            version_only("1.6.0-rc1"),
+        ];
+        let result = latest_only(&versions, true);
+        assert_eq!(result.len(), 1);
+        // `version` stays the machine-readable newest stable release...
+        assert_eq!(result[0].version, "1.5.0");
+        // ...and the newer prerelease is carried separately.
+        assert_eq!(result[0].update_available.as_deref(), Some("1.6.0-rc1"));
+    }
+
+    #[test]
+    fn test_latest_only_with_prereleases_no_hint_when_prerelease_not_ahead() {
+        let versions = vec![version_only("1.5.0"), version_only("1.4.0-rc1")];
+        let result = latest_only(&versions, true);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].version, "1.5.0");
+        assert!(result[0].update_available.is_none());
+    }
+
+    #[test]
+    fn test_display_version_appends_hint_only_when_present() {
+        let mut v = version_only("1.4.0");
+        assert_eq!(display_version(&v), "1.4.0");
+        v.update_available = Some("1.5.0-rc2".to_string());
+        assert_eq!(display_version(&v), "1.4.0 (1.5.0-rc2 available)");
+    }
+
+    #[test]
+    fn test_cache_roundtrip_and_read_cache_miss() {
+        let source = "https://example.invalid/helm-list-charts-test-cache-roundtrip";
+
+        // No cache has been written yet for this source.
+        assert!(read_cache(source).is_none());
+
+        let meta = CacheMeta {
+            etag: Some("\"abc123\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+        };
+        write_cache(source, "entries: {}\n", &meta).expect("write_cache failed");
+
+        let cached = read_cache(source).expect("expected a cache hit after write_cache");
+        assert_eq!(cached.body, "entries: {}\n");
+        assert_eq!(cached.meta.etag.as_deref(), Some("\"abc123\""));
+        assert_eq!(
+            cached.meta.last_modified.as_deref(),
+            Some("Wed, 21 Oct 2015 07:28:00 GMT")
+        );
+
+        // Clean up so repeated test runs don't depend on leftover state.
+        let dir = cache_dir().unwrap();
+        let key = cache_key(source);
+        std::fs::remove_file(dir.join(format!("{key}.yaml"))).ok();
+        std::fs::remove_file(dir.join(format!("{key}.meta.json"))).ok();
+    }
 }